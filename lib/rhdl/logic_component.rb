@@ -32,7 +32,7 @@ class Rhdl::LogicComponent
 
 
   def set!(**values)
-    validate_port_names!(values.keys)
+    validate_input_names!(values.keys)
 
     values.flat_map do |key, val|
       inputs[key].set!(val)
@@ -62,6 +62,17 @@ class Rhdl::LogicComponent
   end
 
 
+  def validate_input_names!(names)
+    validate_port_names!(names)
+
+    names.map(&:to_sym).each do |name|
+      if !input_types.keys.include?(name)
+        raise "#{name} is not an input in #{self.class.name}"
+      end
+    end
+  end
+
+
 
   # Logic Execution Context
   #
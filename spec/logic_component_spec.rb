@@ -0,0 +1,14 @@
+require 'rspec'
+require_relative 'spec_helper'
+
+describe Rhdl::LogicComponent do
+
+  let(:adder) { HalfAdder.new }
+
+  it 'only allows inputs to be set' do
+    expect { adder.set!(a: 1, b: 1) }.not_to raise_error
+    expect { adder.set!(s: 1) }.to raise_error('s is not an input in HalfAdder')
+    expect { adder.set!(x: 1) }.to raise_error('x is not a port in HalfAdder')
+  end
+
+end